@@ -0,0 +1,79 @@
+use crate::{errors::to_py_err, target::Target, wasmer_inner::wasmer_engines};
+use pyo3::prelude::*;
+use std::sync::Arc;
+
+/// The `Universal` engine compiles (or loads) WebAssembly modules
+/// in-process and executes them directly from memory.
+#[pyclass(unsendable)]
+#[text_signature = "(compiler=None, target=None)"]
+pub struct Universal {
+    pub(crate) inner: Arc<wasmer_engines::Universal>,
+}
+
+#[pymethods]
+impl Universal {
+    #[new]
+    fn new(compiler: Option<&PyAny>, target: Option<&Target>) -> PyResult<Self> {
+        let inner = wasmer_engines::universal(compiler, target).map_err(to_py_err)?;
+
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+}
+
+/// The `Dylib` engine compiles WebAssembly modules to a native
+/// shared library and loads it through the system's dynamic linker.
+#[pyclass(unsendable)]
+#[text_signature = "(compiler=None, target=None)"]
+pub struct Dylib {
+    pub(crate) inner: Arc<wasmer_engines::Dylib>,
+}
+
+#[pymethods]
+impl Dylib {
+    #[new]
+    fn new(compiler: Option<&PyAny>, target: Option<&Target>) -> PyResult<Self> {
+        let inner = wasmer_engines::dylib(compiler, target).map_err(to_py_err)?;
+
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+}
+
+/// Deprecated alias of `Universal`.
+#[pyclass(unsendable, extends = Universal)]
+pub struct JIT {}
+
+#[pymethods]
+impl JIT {
+    #[new]
+    fn new(compiler: Option<&PyAny>, target: Option<&Target>) -> PyResult<(Self, Universal)> {
+        Ok((Self {}, Universal::new(compiler, target)?))
+    }
+}
+
+/// Deprecated alias of `Dylib`.
+#[pyclass(unsendable, extends = Dylib)]
+pub struct Native {}
+
+#[pymethods]
+impl Native {
+    #[new]
+    fn new(compiler: Option<&PyAny>, target: Option<&Target>) -> PyResult<(Self, Dylib)> {
+        Ok((Self {}, Dylib::new(compiler, target)?))
+    }
+}
+
+/// Resolves any of the Python-facing engine classes down to the
+/// `wasmer::Engine` trait object `Store::new` expects.
+pub(crate) fn as_wasmer_engine(engine: &PyAny) -> PyResult<Arc<dyn wasmer_engines::Engine + Send + Sync>> {
+    if let Ok(universal) = engine.extract::<PyRef<Universal>>() {
+        Ok(universal.inner.clone())
+    } else if let Ok(dylib) = engine.extract::<PyRef<Dylib>>() {
+        Ok(dylib.inner.clone())
+    } else {
+        Err(to_py_err("`engine` must be a `wasmer.engine.Universal` or `wasmer.engine.Dylib`"))
+    }
+}
@@ -0,0 +1,11 @@
+use pyo3::{exceptions::PyRuntimeError, PyErr};
+use std::fmt::Display;
+
+/// Converts any `Display`-able error (most of `wasmer`'s error types
+/// included) into a Python `RuntimeError`.
+pub fn to_py_err<E>(error: E) -> PyErr
+where
+    E: Display,
+{
+    PyRuntimeError::new_err(error.to_string())
+}
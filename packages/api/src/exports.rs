@@ -0,0 +1,78 @@
+use crate::{errors::to_py_err, externals::Function, wasmer_inner::wasmer};
+use pyo3::prelude::*;
+
+/// The exports of an `Instance`, i.e. everything the guest module
+/// makes available to the host. For now, only function exports can be
+/// read back as `externals::Function`.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store, Module, Instance
+///
+/// store = Store()
+/// module = Module(store, "(module (func (export \"one\") (result i32) (i32.const 1)))")
+/// instance = Instance(module)
+///
+/// assert instance.exports.one() == 1
+/// ```
+#[pyclass(unsendable)]
+pub struct Exports {
+    pub(crate) inner: wasmer::Exports,
+}
+
+#[pymethods]
+impl Exports {
+    fn __getattr__(&self, py: Python, name: &str) -> PyResult<Py<Function>> {
+        let function = self.inner.get_function(name).map_err(to_py_err)?;
+
+        Py::new(
+            py,
+            Function {
+                inner: function.clone(),
+            },
+        )
+    }
+
+    fn __getitem__(&self, py: Python, name: &str) -> PyResult<Py<Function>> {
+        self.__getattr__(py, name)
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        self.inner.get_function(name).is_ok()
+    }
+
+    fn __iter__(slf: PyRef<Self>) -> ExportsIterator {
+        ExportsIterator {
+            names: slf.inner.iter().map(|(name, _)| name.clone()).collect(),
+            index: 0,
+        }
+    }
+}
+
+impl Exports {
+    pub(crate) fn new(inner: wasmer::Exports) -> Self {
+        Self { inner }
+    }
+}
+
+/// An iterator over the names of an `Exports` object.
+#[pyclass]
+pub struct ExportsIterator {
+    names: Vec<String>,
+    index: usize,
+}
+
+#[pymethods]
+impl ExportsIterator {
+    fn __iter__(slf: PyRef<Self>) -> PyRef<Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<Self>) -> Option<String> {
+        let name = slf.names.get(slf.index).cloned();
+        slf.index += 1;
+
+        name
+    }
+}
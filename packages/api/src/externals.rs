@@ -0,0 +1,360 @@
+use crate::{
+    errors::to_py_err,
+    memory,
+    store::Store,
+    types::FunctionType,
+    values::{py_to_val, val_to_py},
+    wasmer_inner::wasmer,
+};
+use pyo3::{prelude::*, types::PyBytes, types::PyTuple};
+use std::cell::Cell;
+
+/// A WebAssembly function. It can come from an `Instance` (it is then
+/// an export), or it can be built from a Python callable so it can be
+/// put in an `ImportObject` and called back from the guest.
+///
+/// ## Example
+///
+/// ```py,ignore
+/// from wasmer import Store, Function, FunctionType, Type
+///
+/// store = Store()
+///
+/// def host_log(message_pointer, message_length):
+///     print("called from the guest!")
+///
+/// host_log_function = Function(
+///     store,
+///     host_log,
+///     FunctionType([Type.I32, Type.I32], [])
+/// )
+/// ```
+#[pyclass(unsendable)]
+#[text_signature = "(store, function, function_type)"]
+pub struct Function {
+    pub(crate) inner: wasmer::Function,
+}
+
+#[pymethods]
+impl Function {
+    #[new]
+    fn new(store: &Store, function: PyObject, function_type: &FunctionType) -> PyResult<Self> {
+        Ok(Self {
+            inner: Self::new_native_function(store, function, function_type.inner.clone()),
+        })
+    }
+
+    /// Like `Function.new`, but `env` is threaded through to
+    /// `function` as its first argument, e.g.
+    /// `def host_log(env, pointer, length): ...`. `env` is copied into
+    /// the function; Wasmer populates that copy with the live `Memory`
+    /// of the `Instance` the function ends up imported into, right
+    /// before the guest runs, so calling `.memory()` on the first
+    /// argument gives the host function a handle to decode (or write
+    /// back) guest data instead of only exchanging scalars.
+    ///
+    /// ## Example
+    ///
+    /// ```py,ignore
+    /// env = FunctionEnv()
+    ///
+    /// def host_log(env, pointer, length):
+    ///     print(env.memory().read_bytes(pointer, length).decode("utf-8"))
+    ///
+    /// host_log_function = Function.new_with_env(
+    ///     store,
+    ///     host_log,
+    ///     FunctionType([Type.I32, Type.I32], []),
+    ///     env,
+    /// )
+    /// ```
+    #[staticmethod]
+    #[text_signature = "(store, function, function_type, env)"]
+    fn new_with_env(
+        store: &Store,
+        function: PyObject,
+        function_type: &FunctionType,
+        env: &FunctionEnv,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            inner: Self::new_native_function_with_env(
+                store,
+                function,
+                function_type.inner.clone(),
+                env.clone(),
+            ),
+        })
+    }
+
+    #[getter]
+    fn ty(&self) -> FunctionType {
+        FunctionType {
+            inner: self.inner.ty().clone(),
+        }
+    }
+
+    #[text_signature = "($self, *arguments)"]
+    #[args(arguments = "*")]
+    fn __call__(&self, py: Python, arguments: &PyTuple) -> PyResult<PyObject> {
+        let function_ty = self.inner.ty();
+        let mut values = Vec::with_capacity(arguments.len());
+
+        for (argument, ty) in arguments.iter().zip(function_ty.params().iter()) {
+            values.push(py_to_val(argument, *ty)?);
+        }
+
+        let results = self.inner.call(&values).map_err(to_py_err)?;
+
+        match results.len() {
+            0 => Ok(py.None()),
+            1 => val_to_py(py, &results[0]),
+            _ => {
+                let results = results
+                    .iter()
+                    .map(|result| val_to_py(py, result))
+                    .collect::<PyResult<Vec<_>>>()?;
+
+                Ok(PyTuple::new(py, results).into())
+            }
+        }
+    }
+}
+
+impl Function {
+    /// Builds a `wasmer::Function` that, when called from the guest,
+    /// re-enters the Python interpreter (acquiring the GIL) to run
+    /// `function` and converts arguments/results through `values`.
+    fn new_native_function(
+        store: &Store,
+        function: PyObject,
+        function_type: wasmer::FunctionType,
+    ) -> wasmer::Function {
+        wasmer::Function::new(&store.inner, &function_type, move |arguments: &[wasmer::Val]| {
+            Python::with_gil(|py| -> Result<Vec<wasmer::Val>, wasmer::RuntimeError> {
+                let python_arguments = arguments
+                    .iter()
+                    .map(|argument| val_to_py(py, argument))
+                    .collect::<PyResult<Vec<_>>>()
+                    .map_err(Self::to_runtime_error)?;
+
+                let result = function
+                    .as_ref(py)
+                    .call1(PyTuple::new(py, python_arguments))
+                    .map_err(Self::to_runtime_error)?;
+
+                Self::results_to_vals(function_type.results(), result).map_err(Self::to_runtime_error)
+            })
+        })
+    }
+
+    /// Like `new_native_function`, but the Python callback additionally
+    /// receives `env` as its first argument. By the time the guest
+    /// calls into it, Wasmer has already populated `env` with the
+    /// `Instance`'s exported `Memory`.
+    fn new_native_function_with_env(
+        store: &Store,
+        function: PyObject,
+        function_type: wasmer::FunctionType,
+        env: FunctionEnv,
+    ) -> wasmer::Function {
+        wasmer::Function::new_with_env(
+            &store.inner,
+            &function_type,
+            env,
+            move |env: &FunctionEnv, arguments: &[wasmer::Val]| {
+                Python::with_gil(|py| -> Result<Vec<wasmer::Val>, wasmer::RuntimeError> {
+                    let env = Py::new(py, env.clone()).map_err(Self::to_runtime_error)?;
+
+                    let mut python_arguments = vec![env.into_py(py)];
+                    python_arguments.extend(
+                        arguments
+                            .iter()
+                            .map(|argument| val_to_py(py, argument))
+                            .collect::<PyResult<Vec<_>>>()
+                            .map_err(Self::to_runtime_error)?,
+                    );
+
+                    let result = function
+                        .as_ref(py)
+                        .call1(PyTuple::new(py, python_arguments))
+                        .map_err(Self::to_runtime_error)?;
+
+                    Self::results_to_vals(function_type.results(), result).map_err(Self::to_runtime_error)
+                })
+            },
+        )
+    }
+
+    /// Converts a Python call's return value back into WebAssembly
+    /// values, following `results`: nothing for zero results, the bare
+    /// value for one, or a tuple for more than one.
+    fn results_to_vals(results: &[wasmer::Type], result: &PyAny) -> PyResult<Vec<wasmer::Val>> {
+        match results.len() {
+            0 => Ok(vec![]),
+            1 => Ok(vec![py_to_val(result, results[0])?]),
+            _ => {
+                let result: &PyTuple = result.extract()?;
+
+                result
+                    .iter()
+                    .zip(results.iter())
+                    .map(|(value, ty)| py_to_val(value, *ty))
+                    .collect()
+            }
+        }
+    }
+
+    fn to_runtime_error(error: PyErr) -> wasmer::RuntimeError {
+        wasmer::RuntimeError::new(error.to_string())
+    }
+}
+
+/// A handle threaded through a host `Function` created with
+/// `Function.new_with_env`. Wasmer fills it in with the `Instance`'s
+/// exported `Memory` right before the guest runs, so a Python host
+/// function can reach into the guest's linear memory — e.g. to decode
+/// a `(pointer, length)` pair into a string, or write a result back —
+/// instead of only exchanging scalars.
+#[pyclass(unsendable)]
+#[derive(Clone, wasmer::WasmerEnv)]
+pub struct FunctionEnv {
+    #[wasmer(export)]
+    memory: wasmer::LazyInit<wasmer::Memory>,
+}
+
+#[pymethods]
+impl FunctionEnv {
+    #[new]
+    fn new() -> Self {
+        Self {
+            memory: Default::default(),
+        }
+    }
+
+    /// The `Instance`'s exported `Memory`. Only available on the
+    /// `FunctionEnv` a host function receives as its first argument,
+    /// once its `Function` has been imported into an `Instance`. The
+    /// `FunctionEnv` passed to `Function.new_with_env` is copied and
+    /// never bound itself, so calling it there raises an error.
+    #[text_signature = "($self)"]
+    fn memory(&self) -> PyResult<Memory> {
+        Ok(Memory {
+            inner: self
+                .memory
+                .get_ref()
+                .ok_or_else(|| to_py_err("This `FunctionEnv` isn't bound to an `Instance` yet"))?
+                .clone(),
+        })
+    }
+}
+
+/// A WebAssembly global variable.
+#[pyclass(unsendable)]
+pub struct Global {
+    pub(crate) inner: wasmer::Global,
+}
+
+/// A WebAssembly memory, i.e. the guest's linear memory.
+#[pyclass(unsendable)]
+pub struct Memory {
+    pub(crate) inner: wasmer::Memory,
+}
+
+#[pymethods]
+impl Memory {
+    /// The memory's current size, in WebAssembly pages (64 KiB each).
+    #[getter]
+    fn size(&self) -> u32 {
+        self.inner.size().0
+    }
+
+    /// Reads `length` bytes out of the guest's linear memory, starting
+    /// at byte offset `offset`.
+    #[text_signature = "($self, offset, length)"]
+    fn read_bytes<'py>(&self, py: Python<'py>, offset: usize, length: usize) -> PyResult<&'py PyBytes> {
+        let view = self.inner.view::<u8>();
+
+        let end = offset
+            .checked_add(length)
+            .filter(|end| *end <= view.len())
+            .ok_or_else(|| to_py_err("Out-of-bounds memory access"))?;
+
+        let bytes: Vec<u8> = view[offset..end].iter().map(Cell::get).collect();
+
+        Ok(PyBytes::new(py, &bytes))
+    }
+
+    /// Writes `bytes` into the guest's linear memory, starting at byte
+    /// offset `offset`.
+    #[text_signature = "($self, offset, bytes)"]
+    fn write_bytes(&self, offset: usize, bytes: &PyBytes) -> PyResult<()> {
+        let view = self.inner.view::<u8>();
+        let bytes = bytes.as_bytes();
+
+        let end = offset
+            .checked_add(bytes.len())
+            .filter(|end| *end <= view.len())
+            .ok_or_else(|| to_py_err("Out-of-bounds memory access"))?;
+
+        for (cell, byte) in view[offset..end].iter().zip(bytes) {
+            cell.set(*byte);
+        }
+
+        Ok(())
+    }
+
+    /// A zero-copy `memory.Buffer` (raw bytes) view over the whole
+    /// guest memory, usable with the Python buffer protocol.
+    #[text_signature = "($self)"]
+    fn buffer(&self) -> memory::Buffer {
+        memory::Buffer::new(self.inner.clone())
+    }
+
+    /// A zero-copy `memory.Uint8Array` view over the whole guest
+    /// memory, usable with the Python buffer protocol.
+    #[text_signature = "($self)"]
+    fn uint8_view(&self) -> memory::Uint8Array {
+        memory::Uint8Array::new(self.inner.clone())
+    }
+
+    /// A zero-copy `memory.Int8Array` view over the whole guest memory,
+    /// usable with the Python buffer protocol.
+    #[text_signature = "($self)"]
+    fn int8_view(&self) -> memory::Int8Array {
+        memory::Int8Array::new(self.inner.clone())
+    }
+
+    /// A zero-copy `memory.Uint16Array` view over the whole guest
+    /// memory, usable with the Python buffer protocol.
+    #[text_signature = "($self)"]
+    fn uint16_view(&self) -> memory::Uint16Array {
+        memory::Uint16Array::new(self.inner.clone())
+    }
+
+    /// A zero-copy `memory.Int16Array` view over the whole guest
+    /// memory, usable with the Python buffer protocol.
+    #[text_signature = "($self)"]
+    fn int16_view(&self) -> memory::Int16Array {
+        memory::Int16Array::new(self.inner.clone())
+    }
+
+    /// A zero-copy `memory.Uint32Array` view over the whole guest
+    /// memory, usable with the Python buffer protocol.
+    #[text_signature = "($self)"]
+    fn uint32_view(&self) -> memory::Uint32Array {
+        memory::Uint32Array::new(self.inner.clone())
+    }
+
+    /// A zero-copy `memory.Int32Array` view over the whole guest
+    /// memory, usable with the Python buffer protocol.
+    #[text_signature = "($self)"]
+    fn int32_view(&self) -> memory::Int32Array {
+        memory::Int32Array::new(self.inner.clone())
+    }
+}
+
+/// A WebAssembly table.
+#[pyclass(unsendable)]
+pub struct Table {
+    pub(crate) inner: wasmer::Table,
+}
@@ -0,0 +1,165 @@
+use crate::{externals::Function, wasmer_inner::wasmer};
+use pyo3::{prelude::*, types::PyDict};
+use std::collections::HashMap;
+
+type Namespace = HashMap<String, wasmer::Extern>;
+
+/// A set of imports to instantiate a `Module` with.
+///
+/// Most of the time an `ImportObject` is produced by Wasmer itself,
+/// e.g. `wasi::Environment.generate_import_object`. It can also be
+/// built or extended by hand so a module can call back into
+/// Python-defined host functions (`externals::Function`), in addition
+/// to (or instead of) the imports Wasmer generated.
+///
+/// `register`/`extend` merge at `(namespace, name)` granularity: a
+/// name they define replaces only that name, leaving every other name
+/// already registered in that namespace (whether it came from `base`
+/// or an earlier `register`/`extend` call) untouched. This is what
+/// makes it possible to override or augment a single WASI import (e.g.
+/// one name inside `wasi_snapshot_preview1`) without losing the rest
+/// of it.
+///
+/// ## Example
+///
+/// ```py,ignore
+/// from wasmer import Store, Function, FunctionType, Type, ImportObject
+///
+/// store = Store()
+///
+/// def host_log(message):
+///     print(message)
+///
+/// import_object = ImportObject()
+/// import_object.register(
+///     "env",
+///     {
+///         "host_log": Function(store, host_log, FunctionType([Type.I32], [])),
+///     }
+/// )
+/// ```
+#[pyclass(unsendable)]
+#[text_signature = "()"]
+pub struct ImportObject {
+    /// Imports generated by Wasmer (e.g. the WASI imports), used as
+    /// the fallback layer. `None` for an `ImportObject` built by hand
+    /// from Python.
+    base: Option<wasmer::ImportObject>,
+    /// Names registered (or merged in via `extend`) from Python, keyed
+    /// by namespace; always takes precedence over `base`'s same
+    /// `(namespace, name)` entry.
+    namespaces: HashMap<String, Namespace>,
+}
+
+#[pymethods]
+impl ImportObject {
+    #[new]
+    fn new() -> Self {
+        Self {
+            base: None,
+            namespaces: HashMap::new(),
+        }
+    }
+
+    /// Registers `externals` (a `{name: Function}` dictionary; for now
+    /// only `externals::Function` host functions are supported) under
+    /// `namespace`. Each name replaces only that name in `namespace`;
+    /// any other name already present (from `base`, or an earlier
+    /// `register`/`extend` call) is kept.
+    #[text_signature = "($self, namespace, externals)"]
+    fn register(&mut self, namespace: String, externals: &PyDict) -> PyResult<()> {
+        let entry = self.namespaces.entry(namespace).or_insert_with(HashMap::new);
+
+        for (name, external) in externals.iter() {
+            let name: String = name.extract()?;
+            let function: PyRef<Function> = external.extract()?;
+
+            entry.insert(name, wasmer::Extern::Function(function.inner.clone()));
+        }
+
+        Ok(())
+    }
+
+    /// Merges `other` into `self`: every `(namespace, name)` `other`
+    /// defines replaces `self`'s (or `base`'s) entry of the same name,
+    /// while every other name is kept untouched. This is how a
+    /// user-built `ImportObject` of Python host functions can override
+    /// (or augment) the imports generated by
+    /// `wasi::Environment.generate_import_object`, down to a single
+    /// name inside an existing namespace.
+    ///
+    /// Only the names registered on `other` (through `register` or
+    /// `extend`) are merged; Wasmer-generated imports that `other`
+    /// wraps (e.g. one returned by `generate_import_object`) are not
+    /// carried over.
+    ///
+    /// ## Example
+    ///
+    /// ```py,ignore
+    /// import_object = wasi_env.generate_import_object(store, version)
+    /// import_object.extend(overrides)
+    /// ```
+    #[text_signature = "($self, other)"]
+    fn extend(&mut self, other: &Self) {
+        for (namespace, overrides) in &other.namespaces {
+            let entry = self
+                .namespaces
+                .entry(namespace.clone())
+                .or_insert_with(HashMap::new);
+
+            for (name, extern_) in overrides {
+                entry.insert(name.clone(), extern_.clone());
+            }
+        }
+    }
+
+    fn contains_namespace(&self, namespace: &str) -> bool {
+        self.namespaces.contains_key(namespace)
+            || self
+                .base
+                .as_ref()
+                .map_or(false, |base| base.contains_namespace(namespace))
+    }
+}
+
+impl ImportObject {
+    /// Wraps Wasmer-generated imports (e.g. WASI's) as the fallback
+    /// layer of a fresh `ImportObject`, so `register`/`extend` calls on
+    /// it can override or augment specific `(namespace, name)` imports
+    /// while leaving the rest untouched.
+    pub(crate) fn from_wasmer(base: wasmer::ImportObject) -> Self {
+        Self {
+            base: Some(base),
+            namespaces: HashMap::new(),
+        }
+    }
+
+    /// Materializes this `ImportObject` into the `wasmer::ImportObject`
+    /// Wasmer's `Instance::new` expects: for every namespace that was
+    /// registered or merged in, `base`'s own names in that namespace
+    /// (if any) are kept and the registered names are overlaid on top,
+    /// so a single-name override can't silently drop its siblings.
+    pub(crate) fn to_wasmer(&self) -> wasmer::ImportObject {
+        let mut import_object = self.base.clone().unwrap_or_default();
+
+        for (namespace, overrides) in &self.namespaces {
+            let mut merged = import_object
+                .get_namespace_exports(namespace)
+                .map(|exports| {
+                    exports
+                        .iter()
+                        .map(|(name, extern_)| (name.clone(), extern_.clone()))
+                        .collect()
+                })
+                .unwrap_or_else(HashMap::new);
+
+            for (name, extern_) in overrides {
+                merged.insert(name.clone(), extern_.clone());
+            }
+
+            import_object.register(namespace.clone(), merged);
+        }
+
+        import_object
+    }
+}
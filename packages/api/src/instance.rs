@@ -0,0 +1,41 @@
+use crate::{
+    errors::to_py_err, exports::Exports, import_object::ImportObject, module::Module,
+    wasmer_inner::wasmer,
+};
+use pyo3::prelude::*;
+
+/// An instantiated WebAssembly `Module`, ready to run.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store, Module, Instance
+///
+/// store = Store()
+/// module = Module(store, "(module (func (export \"sum\") (param i32 i32) (result i32) local.get 0 local.get 1 i32.add))")
+/// instance = Instance(module)
+///
+/// assert instance.exports.sum(1, 2) == 3
+/// ```
+#[pyclass(unsendable)]
+#[text_signature = "(module, import_object=None)"]
+pub struct Instance {
+    #[pyo3(get)]
+    exports: Py<Exports>,
+    pub(crate) inner: wasmer::Instance,
+}
+
+#[pymethods]
+impl Instance {
+    #[new]
+    fn new(py: Python, module: &Module, import_object: Option<&ImportObject>) -> PyResult<Self> {
+        let wasmer_import_object = import_object
+            .map(ImportObject::to_wasmer)
+            .unwrap_or_default();
+
+        let inner = wasmer::Instance::new(&module.inner, &wasmer_import_object).map_err(to_py_err)?;
+        let exports = Py::new(py, Exports::new(inner.exports.clone()))?;
+
+        Ok(Self { exports, inner })
+    }
+}
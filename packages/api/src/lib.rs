@@ -124,6 +124,7 @@ fn wasmer(py: Python, module: &PyModule) -> PyResult<()> {
     module.add_class::<exports::Exports>()?;
     module.add_class::<exports::ExportsIterator>()?;
     module.add_class::<externals::Function>()?;
+    module.add_class::<externals::FunctionEnv>()?;
     module.add_class::<externals::Global>()?;
     module.add_class::<externals::Memory>()?;
     module.add_class::<externals::Table>()?;
@@ -279,8 +280,9 @@ fn engine(_py: Python, module: &PyModule) -> PyResult<()> {
 ///     """
 /// )
 ///
-/// # What's next? Serialize the module, and execute it on the
-/// # targeted host.
+/// # What's next? Serialize the module, ship the bytes to the
+/// # targeted host, and deserialize them there with a headless store.
+/// serialized_module_bytes = module.serialize()
 /// ```
 #[pymodule]
 fn target(_py: Python, module: &PyModule) -> PyResult<()> {
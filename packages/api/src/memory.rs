@@ -0,0 +1,154 @@
+use crate::wasmer_inner::wasmer;
+use pyo3::{exceptions::PyBufferError, ffi, prelude::*};
+use std::os::raw::c_int;
+
+macro_rules! memory_view {
+    ($name:ident, $ty:ty, $format:expr, $doc:expr) => {
+        #[doc = $doc]
+        #[pyclass]
+        pub struct $name {
+            memory: wasmer::Memory,
+        }
+
+        impl $name {
+            pub(crate) fn new(memory: wasmer::Memory) -> Self {
+                Self { memory }
+            }
+        }
+
+        #[pyproto]
+        impl pyo3::class::PyBufferProtocol for $name {
+            fn bf_getbuffer(slf: PyRefMut<Self>, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+                fill_memory_view(
+                    view,
+                    slf.into_py(slf.py()),
+                    slf.memory.data_ptr(),
+                    slf.memory.data_size() as isize,
+                    std::mem::size_of::<$ty>() as isize,
+                    $format,
+                    flags,
+                )
+            }
+
+            fn bf_releasebuffer(_slf: PyRefMut<Self>, view: *mut ffi::Py_buffer) {
+                release_memory_view(view);
+            }
+        }
+    };
+}
+
+memory_view!(Int8Array, i8, "b\0", "A zero-copy `int8` view over a `externals::Memory`.");
+memory_view!(Uint8Array, u8, "B\0", "A zero-copy `uint8` view over a `externals::Memory`.");
+memory_view!(Int16Array, i16, "h\0", "A zero-copy `int16` view over a `externals::Memory`.");
+memory_view!(Uint16Array, u16, "H\0", "A zero-copy `uint16` view over a `externals::Memory`.");
+memory_view!(Int32Array, i32, "i\0", "A zero-copy `int32` view over a `externals::Memory`.");
+memory_view!(Uint32Array, u32, "I\0", "A zero-copy `uint32` view over a `externals::Memory`.");
+
+/// A zero-copy, raw byte view over a `externals::Memory`, exposed
+/// through the Python buffer protocol (e.g. `bytes(buffer)`,
+/// `memoryview(buffer)`). Equivalent to `Uint8Array`, kept as its own
+/// type for readability at call sites.
+#[pyclass]
+pub struct Buffer {
+    memory: wasmer::Memory,
+}
+
+impl Buffer {
+    pub(crate) fn new(memory: wasmer::Memory) -> Self {
+        Self { memory }
+    }
+}
+
+#[pyproto]
+impl pyo3::class::PyBufferProtocol for Buffer {
+    fn bf_getbuffer(slf: PyRefMut<Self>, view: *mut ffi::Py_buffer, flags: c_int) -> PyResult<()> {
+        fill_memory_view(
+            view,
+            slf.into_py(slf.py()),
+            slf.memory.data_ptr(),
+            slf.memory.data_size() as isize,
+            1,
+            "B\0",
+            flags,
+        )
+    }
+
+    fn bf_releasebuffer(_slf: PyRefMut<Self>, view: *mut ffi::Py_buffer) {
+        release_memory_view(view);
+    }
+}
+
+/// Fills a `Py_buffer` so it exposes the `length`-byte region at
+/// `pointer`, reinterpreted as `item_size`-wide elements described by
+/// `format` (a NUL-terminated `struct`-style format string).
+///
+/// The buffer-protocol invariant is `len == itemsize * shape[0]`:
+/// `len` is always the byte length, but `shape[0]` must be the
+/// *element* count, not the byte count, or consumers (e.g.
+/// `memoryview`) will read up to `itemsize` times past the real data.
+fn fill_memory_view(
+    view: *mut ffi::Py_buffer,
+    owner: PyObject,
+    pointer: *mut u8,
+    length: isize,
+    item_size: isize,
+    format: &'static str,
+    flags: c_int,
+) -> PyResult<()> {
+    if view.is_null() {
+        return Err(PyBufferError::new_err("`Py_buffer` is null"));
+    }
+
+    if item_size <= 0 || length % item_size != 0 {
+        return Err(PyBufferError::new_err(
+            "Memory size is not a multiple of this view's item size",
+        ));
+    }
+
+    let element_count = length / item_size;
+
+    unsafe {
+        (*view).obj = owner.into_ptr();
+        (*view).buf = pointer as *mut _;
+        (*view).len = length;
+        (*view).readonly = 0;
+        (*view).itemsize = item_size;
+        (*view).format = if flags & ffi::PyBUF_FORMAT == ffi::PyBUF_FORMAT {
+            format.as_ptr() as *mut _
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).ndim = 1;
+
+        // `shape` must point at storage that outlives this call (the
+        // consumer reads it for the buffer's lifetime), so it can't
+        // reuse a field of `view` like `strides` does below. Leak a
+        // single-element array and reclaim it in `release_memory_view`.
+        if flags & ffi::PyBUF_ND == ffi::PyBUF_ND {
+            let shape = Box::into_raw(Box::new(element_count));
+            (*view).shape = shape;
+            (*view).internal = shape as *mut _;
+        } else {
+            (*view).shape = std::ptr::null_mut();
+            (*view).internal = std::ptr::null_mut();
+        }
+
+        (*view).strides = if flags & ffi::PyBUF_STRIDES == ffi::PyBUF_STRIDES {
+            &mut (*view).itemsize
+        } else {
+            std::ptr::null_mut()
+        };
+        (*view).suboffsets = std::ptr::null_mut();
+    }
+
+    Ok(())
+}
+
+fn release_memory_view(view: *mut ffi::Py_buffer) {
+    unsafe {
+        if !view.is_null() && !(*view).internal.is_null() {
+            drop(Box::from_raw((*view).internal as *mut isize));
+            (*view).internal = std::ptr::null_mut();
+        }
+    }
+}
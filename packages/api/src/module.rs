@@ -0,0 +1,130 @@
+use crate::{errors::to_py_err, store::Store, wasmer_inner::wasmer, wat};
+use pyo3::{
+    prelude::*,
+    types::{PyBytes, PyString},
+};
+
+/// A compiled WebAssembly module.
+///
+/// A `Module` is built from WebAssembly bytes (either the binary
+/// format, or the text format which is translated under the hood with
+/// `wat2wasm`) and a `Store`. Once instantiated into an `Instance`, it
+/// can be run.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store, Module
+///
+/// store = Store()
+/// module = Module(store, "(module)")
+/// ```
+#[pyclass(unsendable)]
+#[text_signature = "(store, bytes)"]
+pub struct Module {
+    pub(crate) inner: wasmer::Module,
+}
+
+#[pymethods]
+impl Module {
+    #[new]
+    fn new(store: &Store, bytes: &PyAny) -> PyResult<Self> {
+        let bytes = Self::normalize_bytes(bytes)?;
+        let inner = wasmer::Module::new(&store.inner, bytes).map_err(to_py_err)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Checks whether `bytes` looks like a valid WebAssembly module
+    /// for `store`'s engine, without fully compiling it.
+    #[staticmethod]
+    #[text_signature = "(store, bytes)"]
+    fn validate(store: &Store, bytes: &PyAny) -> PyResult<bool> {
+        let bytes = Self::normalize_bytes(bytes)?;
+
+        Ok(wasmer::Module::validate(&store.inner, bytes).is_ok())
+    }
+
+    #[getter]
+    fn name(&self) -> Option<&str> {
+        self.inner.name()
+    }
+
+    /// Serializes the module into an artifact that can be persisted
+    /// (e.g. to disk) and later loaded back with `Module.deserialize`
+    /// without recompiling. This is the missing half of the
+    /// cross-compilation workflow `target.Target` enables: compile
+    /// once with a compiler attached to a `Dylib`/`Universal` engine,
+    /// ship the bytes, and instantiate them anywhere with a headless
+    /// `Store` (an engine with no compiler).
+    ///
+    /// ## Example
+    ///
+    /// ```py,ignore
+    /// serialized_module_bytes = module.serialize()
+    /// open("module.bin", "wb").write(serialized_module_bytes)
+    /// ```
+    #[text_signature = "($self)"]
+    fn serialize<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        let serialized = self.inner.serialize().map_err(to_py_err)?;
+
+        Ok(PyBytes::new(py, &serialized))
+    }
+
+    /// Deserializes a module previously produced by `Module.serialize`.
+    ///
+    /// `store` may use a headless engine (no compiler attached): since
+    /// the artifact is already compiled, loading it back doesn't need
+    /// one. Unlike `Module.deserialize_unchecked`, this runs a basic
+    /// header/version sanity check before loading, which rejects
+    /// obviously wrong input (e.g. arbitrary bytes, or bytes from an
+    /// incompatible Wasmer version) with an error. That check is not a
+    /// security boundary: as with `deserialize_unchecked`, only load
+    /// bytes produced by a `Module.serialize` call you trust, since a
+    /// malformed or adversarial artifact can still crash the process.
+    ///
+    /// ## Example
+    ///
+    /// ```py,ignore
+    /// serialized_module_bytes = open("module.bin", "rb").read()
+    /// module = Module.deserialize(store, serialized_module_bytes)
+    /// ```
+    #[staticmethod]
+    #[text_signature = "(store, serialized_bytes)"]
+    fn deserialize(store: &Store, serialized_bytes: &PyBytes) -> PyResult<Self> {
+        let inner = unsafe { wasmer::Module::deserialize(&store.inner, serialized_bytes.as_bytes()) }
+            .map_err(to_py_err)?;
+
+        Ok(Self { inner })
+    }
+
+    /// Like `Module.deserialize`, but skips the artifact's integrity
+    /// check entirely. Only use this on bytes produced by a
+    /// `Module.serialize` call you trust (e.g. your own on-disk
+    /// cache); corrupted or adversarial input can crash the process.
+    #[staticmethod]
+    #[text_signature = "(store, serialized_bytes)"]
+    fn deserialize_unchecked(store: &Store, serialized_bytes: &PyBytes) -> PyResult<Self> {
+        let inner =
+            unsafe { wasmer::Module::deserialize_unchecked(&store.inner, serialized_bytes.as_bytes()) }
+                .map_err(to_py_err)?;
+
+        Ok(Self { inner })
+    }
+}
+
+impl Module {
+    /// A `Module` can be built either from raw WebAssembly bytes, or
+    /// from a WAT string, which is translated on the fly.
+    fn normalize_bytes(bytes: &PyAny) -> PyResult<Vec<u8>> {
+        if let Ok(bytes) = bytes.downcast::<PyBytes>() {
+            Ok(bytes.as_bytes().to_vec())
+        } else if let Ok(string) = bytes.downcast::<PyString>() {
+            let py = bytes.py();
+
+            Ok(wat::wat2wasm(py, string.to_string())?.as_bytes().to_vec())
+        } else {
+            Err(to_py_err("`bytes` must be a `bytes` or a `str`"))
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use crate::{engines::as_wasmer_engine, wasmer_inner::wasmer};
+use pyo3::prelude::*;
+
+/// Holds the engine (`wasmer.engine`) used to compile and run
+/// WebAssembly modules, plus the runtime's tunables.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import Store
+///
+/// store = Store()
+/// ```
+#[pyclass(unsendable)]
+#[text_signature = "(engine=None)"]
+pub struct Store {
+    pub(crate) inner: wasmer::Store,
+}
+
+#[pymethods]
+impl Store {
+    #[new]
+    fn new(engine: Option<&PyAny>) -> PyResult<Self> {
+        let inner = match engine {
+            Some(engine) => wasmer::Store::new(&*as_wasmer_engine(engine)?),
+            None => wasmer::Store::default(),
+        };
+
+        Ok(Self { inner })
+    }
+}
@@ -0,0 +1,68 @@
+use crate::{errors::to_py_err, wasmer_inner::wasmer_types};
+use pyo3::prelude::*;
+
+/// A target triple, e.g. `x86_64-linux-musl`, describing the
+/// architecture, vendor, operating system and environment to compile
+/// for.
+#[pyclass]
+#[text_signature = "(triple)"]
+pub struct Triple {
+    pub(crate) inner: wasmer_types::Triple,
+}
+
+#[pymethods]
+impl Triple {
+    #[new]
+    fn new(triple: String) -> PyResult<Self> {
+        Ok(Self {
+            inner: triple.parse().map_err(to_py_err)?,
+        })
+    }
+}
+
+/// The set of CPU features a target is allowed to use, e.g. `sse2`.
+#[pyclass]
+#[text_signature = "()"]
+pub struct CpuFeatures {
+    pub(crate) inner: wasmer_types::CpuFeature,
+}
+
+#[pymethods]
+impl CpuFeatures {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: wasmer_types::CpuFeature::set(),
+        }
+    }
+
+    #[text_signature = "($self, feature)"]
+    fn add(&mut self, feature: String) -> PyResult<()> {
+        self.inner |= feature.parse().map_err(to_py_err)?;
+
+        Ok(())
+    }
+}
+
+/// A compilation target, combining a `Triple` with optional
+/// `CpuFeatures`. Used by `engine.Dylib` and `engine.Universal` to
+/// cross-compile a module for a host other than the current one.
+#[pyclass]
+#[text_signature = "(triple, cpu_features=None)"]
+pub struct Target {
+    pub(crate) inner: wasmer_types::Target,
+}
+
+#[pymethods]
+impl Target {
+    #[new]
+    fn new(triple: &Triple, cpu_features: Option<&CpuFeatures>) -> Self {
+        let cpu_features = cpu_features
+            .map(|cpu_features| cpu_features.inner.clone())
+            .unwrap_or_default();
+
+        Self {
+            inner: wasmer_types::Target::new(triple.inner.clone(), cpu_features),
+        }
+    }
+}
@@ -0,0 +1,136 @@
+use crate::wasmer_inner::wasmer;
+use pyo3::prelude::*;
+
+/// A WebAssembly value type.
+#[derive(Copy, Clone)]
+#[pyclass]
+pub enum Type {
+    I32,
+    I64,
+    F32,
+    F64,
+    V128,
+    ExternRef,
+    FuncRef,
+}
+
+impl Type {
+    pub fn iter() -> impl Iterator<Item = Type> {
+        [
+            Type::I32,
+            Type::I64,
+            Type::F32,
+            Type::F64,
+            Type::V128,
+            Type::ExternRef,
+            Type::FuncRef,
+        ]
+        .into_iter()
+    }
+}
+
+impl From<Type> for &'static str {
+    fn from(ty: Type) -> Self {
+        match ty {
+            Type::I32 => "I32",
+            Type::I64 => "I64",
+            Type::F32 => "F32",
+            Type::F64 => "F64",
+            Type::V128 => "V128",
+            Type::ExternRef => "ExternRef",
+            Type::FuncRef => "FuncRef",
+        }
+    }
+}
+
+impl From<Type> for wasmer::Type {
+    fn from(ty: Type) -> Self {
+        match ty {
+            Type::I32 => wasmer::Type::I32,
+            Type::I64 => wasmer::Type::I64,
+            Type::F32 => wasmer::Type::F32,
+            Type::F64 => wasmer::Type::F64,
+            Type::V128 => wasmer::Type::V128,
+            Type::ExternRef => wasmer::Type::ExternRef,
+            Type::FuncRef => wasmer::Type::FuncRef,
+        }
+    }
+}
+
+impl From<wasmer::Type> for Type {
+    fn from(ty: wasmer::Type) -> Self {
+        match ty {
+            wasmer::Type::I32 => Type::I32,
+            wasmer::Type::I64 => Type::I64,
+            wasmer::Type::F32 => Type::F32,
+            wasmer::Type::F64 => Type::F64,
+            wasmer::Type::V128 => Type::V128,
+            wasmer::Type::ExternRef => Type::ExternRef,
+            wasmer::Type::FuncRef => Type::FuncRef,
+        }
+    }
+}
+
+/// Describes the signature (parameter and result types) of a
+/// `Function`.
+#[pyclass]
+#[text_signature = "(params, results)"]
+#[derive(Clone)]
+pub struct FunctionType {
+    pub(crate) inner: wasmer::FunctionType,
+}
+
+#[pymethods]
+impl FunctionType {
+    #[new]
+    fn new(params: Vec<Type>, results: Vec<Type>) -> Self {
+        Self {
+            inner: wasmer::FunctionType::new(
+                params.into_iter().map(Into::into).collect::<Vec<_>>(),
+                results.into_iter().map(Into::into).collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    #[getter]
+    fn params(&self) -> Vec<Type> {
+        self.inner.params().iter().copied().map(Into::into).collect()
+    }
+
+    #[getter]
+    fn results(&self) -> Vec<Type> {
+        self.inner.results().iter().copied().map(Into::into).collect()
+    }
+}
+
+/// Describes a `Global`'s value type and mutability.
+#[pyclass]
+pub struct GlobalType {
+    pub(crate) inner: wasmer::GlobalType,
+}
+
+/// Describes a `Memory`'s minimum and maximum size, in WebAssembly
+/// pages.
+#[pyclass]
+pub struct MemoryType {
+    pub(crate) inner: wasmer::MemoryType,
+}
+
+/// Describes a `Table`'s element type and size bounds.
+#[pyclass]
+pub struct TableType {
+    pub(crate) inner: wasmer::TableType,
+}
+
+/// Describes one of a `Module`'s exports.
+#[pyclass]
+pub struct ExportType {
+    pub(crate) name: String,
+}
+
+/// Describes one of a `Module`'s imports.
+#[pyclass]
+pub struct ImportType {
+    pub(crate) module: String,
+    pub(crate) name: String,
+}
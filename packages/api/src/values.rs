@@ -0,0 +1,47 @@
+use crate::{errors::to_py_err, types::Type, wasmer_inner::wasmer};
+use pyo3::prelude::*;
+
+/// A WebAssembly value, tagged with its `Type`.
+#[pyclass]
+#[derive(Clone)]
+pub struct Value {
+    pub(crate) inner: wasmer::Val,
+}
+
+#[pymethods]
+impl Value {
+    #[getter]
+    fn ty(&self) -> Type {
+        self.inner.ty().into()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("{:?}", self.inner)
+    }
+}
+
+impl From<wasmer::Val> for Value {
+    fn from(inner: wasmer::Val) -> Self {
+        Self { inner }
+    }
+}
+
+pub(crate) fn py_to_val(any: &PyAny, ty: wasmer::Type) -> PyResult<wasmer::Val> {
+    Ok(match ty {
+        wasmer::Type::I32 => wasmer::Val::I32(any.extract()?),
+        wasmer::Type::I64 => wasmer::Val::I64(any.extract()?),
+        wasmer::Type::F32 => wasmer::Val::F32(any.extract()?),
+        wasmer::Type::F64 => wasmer::Val::F64(any.extract()?),
+        _ => return Err(to_py_err("Unsupported value type for this conversion")),
+    })
+}
+
+pub(crate) fn val_to_py(py: Python, value: &wasmer::Val) -> PyResult<PyObject> {
+    Ok(match value {
+        wasmer::Val::I32(value) => value.into_py(py),
+        wasmer::Val::I64(value) => value.into_py(py),
+        wasmer::Val::F32(value) => value.into_py(py),
+        wasmer::Val::F64(value) => value.into_py(py),
+        _ => return Err(to_py_err("Unsupported value type for this conversion")),
+    })
+}
@@ -0,0 +1,294 @@
+use crate::{
+    errors::to_py_err, import_object::ImportObject, module::Module, store::Store,
+    wasmer_inner::wasmer_wasi,
+};
+use pyo3::{prelude::*, types::PyBytes, types::PyDict};
+use std::{
+    io::{Read, Write},
+    path::PathBuf,
+    sync::{Arc, Mutex},
+};
+
+/// The version of WASI in use, detected from a module's import
+/// namespaces by `get_version`.
+#[derive(Copy, Clone)]
+#[pyclass]
+pub enum Version {
+    Latest,
+    Snapshot0,
+    Snapshot1,
+    Unknown,
+}
+
+impl Version {
+    pub fn iter() -> impl Iterator<Item = Version> {
+        [
+            Version::Latest,
+            Version::Snapshot0,
+            Version::Snapshot1,
+            Version::Unknown,
+        ]
+        .into_iter()
+    }
+}
+
+impl From<Version> for &'static str {
+    fn from(version: Version) -> Self {
+        match version {
+            Version::Latest => "Latest",
+            Version::Snapshot0 => "Snapshot0",
+            Version::Snapshot1 => "Snapshot1",
+            Version::Unknown => "Unknown",
+        }
+    }
+}
+
+impl From<wasmer_wasi::WasiVersion> for Version {
+    fn from(version: wasmer_wasi::WasiVersion) -> Self {
+        match version {
+            wasmer_wasi::WasiVersion::Latest => Version::Latest,
+            wasmer_wasi::WasiVersion::Snapshot0 => Version::Snapshot0,
+            wasmer_wasi::WasiVersion::Snapshot1 => Version::Snapshot1,
+        }
+    }
+}
+
+impl From<Version> for wasmer_wasi::WasiVersion {
+    fn from(version: Version) -> Self {
+        match version {
+            Version::Latest => wasmer_wasi::WasiVersion::Latest,
+            Version::Snapshot0 => wasmer_wasi::WasiVersion::Snapshot0,
+            Version::Snapshot1 => wasmer_wasi::WasiVersion::Snapshot1,
+            Version::Unknown => wasmer_wasi::WasiVersion::Latest,
+        }
+    }
+}
+
+/// Detects the version of WASI being used based on a module's import
+/// namespaces.
+///
+/// A strict detection expects that all imports live in a single WASI
+/// namespace. A non-strict detection expects that at least one WASI
+/// namespace exists to detect the version. The strict detection is
+/// faster than the non-strict one.
+pub fn get_version(module: &Module, strict: bool) -> Option<Version> {
+    wasmer_wasi::get_wasi_version(&module.inner, strict).map(Into::into)
+}
+
+/// Builds a `wasi::Environment`, i.e. the WASI state (arguments,
+/// environment variables, preopened directories, stdio) a WASI guest
+/// will see once instantiated.
+///
+/// ## Example
+///
+/// ```py
+/// from wasmer import wasi
+///
+/// wasi_state_builder = wasi.StateBuilder('test-program')
+/// ```
+#[pyclass]
+#[text_signature = "(program_name)"]
+pub struct StateBuilder {
+    inner: wasmer_wasi::WasiStateBuilder,
+    captured_stdout: Option<wasmer_wasi::Pipe>,
+    captured_stderr: Option<wasmer_wasi::Pipe>,
+}
+
+#[pymethods]
+impl StateBuilder {
+    #[new]
+    fn new(program_name: String) -> Self {
+        Self {
+            inner: wasmer_wasi::WasiState::new(&program_name),
+            captured_stdout: None,
+            captured_stderr: None,
+        }
+    }
+
+    /// Adds a command-line argument for the WASI guest.
+    #[text_signature = "($self, argument)"]
+    fn argument(mut slf: PyRefMut<Self>, argument: String) -> PyRefMut<Self> {
+        slf.inner.arg(argument);
+
+        slf
+    }
+
+    /// Sets a single environment variable visible to the WASI guest.
+    #[text_signature = "($self, key, value)"]
+    fn environment(mut slf: PyRefMut<Self>, key: String, value: String) -> PyRefMut<Self> {
+        slf.inner.env(key, value);
+
+        slf
+    }
+
+    /// Sets several environment variables at once from a `{key: value}`
+    /// dictionary.
+    #[text_signature = "($self, environments)"]
+    fn environments(mut slf: PyRefMut<Self>, environments: &PyDict) -> PyResult<PyRefMut<Self>> {
+        for (key, value) in environments.iter() {
+            let key: String = key.extract()?;
+            let value: String = value.extract()?;
+
+            slf.inner.env(key, value);
+        }
+
+        Ok(slf)
+    }
+
+    /// Preopens `path` from the host filesystem, so the WASI guest can
+    /// access it under the same path.
+    #[text_signature = "($self, path)"]
+    fn preopen_directory(mut slf: PyRefMut<Self>, path: PathBuf) -> PyResult<PyRefMut<Self>> {
+        slf.inner.preopen_dir(path).map_err(to_py_err)?;
+
+        Ok(slf)
+    }
+
+    /// Mounts `host_path` from the host filesystem into the WASI guest
+    /// under the `alias` path.
+    #[text_signature = "($self, alias, host_path)"]
+    fn map_directory(
+        mut slf: PyRefMut<Self>,
+        alias: String,
+        host_path: PathBuf,
+    ) -> PyResult<PyRefMut<Self>> {
+        slf.inner.map_dir(&alias, host_path).map_err(to_py_err)?;
+
+        Ok(slf)
+    }
+
+    /// Feeds `stdin_bytes` to the WASI guest's standard input.
+    #[text_signature = "($self, stdin_bytes)"]
+    fn stdin(mut slf: PyRefMut<Self>, stdin_bytes: &PyBytes) -> PyResult<PyRefMut<Self>> {
+        let mut pipe = wasmer_wasi::Pipe::new();
+        pipe.write_all(stdin_bytes.as_bytes()).map_err(to_py_err)?;
+
+        slf.inner.stdin(Box::new(pipe));
+
+        Ok(slf)
+    }
+
+    /// Captures everything the WASI guest writes to its standard
+    /// output, so it can be read back afterwards with
+    /// `Environment.read_stdout`.
+    #[text_signature = "($self)"]
+    fn capture_stdout(mut slf: PyRefMut<Self>) -> PyRefMut<Self> {
+        let pipe = wasmer_wasi::Pipe::new();
+
+        slf.captured_stdout = Some(pipe.clone());
+        slf.inner.stdout(Box::new(pipe));
+
+        slf
+    }
+
+    /// Captures everything the WASI guest writes to its standard
+    /// error, so it can be read back afterwards with
+    /// `Environment.read_stderr`.
+    #[text_signature = "($self)"]
+    fn capture_stderr(mut slf: PyRefMut<Self>) -> PyRefMut<Self> {
+        let pipe = wasmer_wasi::Pipe::new();
+
+        slf.captured_stderr = Some(pipe.clone());
+        slf.inner.stderr(Box::new(pipe));
+
+        slf
+    }
+
+    /// Finalizes the builder into a `wasi::Environment`.
+    #[text_signature = "($self)"]
+    fn finalize(mut slf: PyRefMut<Self>) -> PyResult<Environment> {
+        let captured_stdout = slf.captured_stdout.take();
+        let captured_stderr = slf.captured_stderr.take();
+        let inner = slf.inner.finalize().map_err(to_py_err)?;
+
+        Ok(Environment {
+            inner: Arc::new(Mutex::new(inner)),
+            captured_stdout,
+            captured_stderr,
+        })
+    }
+}
+
+/// The WASI state of a guest, produced by `StateBuilder.finalize`. It
+/// holds the WASI memory and is used to generate the `ImportObject`
+/// needed to instantiate a WASI-enabled `Module`.
+#[pyclass(unsendable)]
+pub struct Environment {
+    pub(crate) inner: Arc<Mutex<wasmer_wasi::WasiEnv>>,
+    captured_stdout: Option<wasmer_wasi::Pipe>,
+    captured_stderr: Option<wasmer_wasi::Pipe>,
+}
+
+#[pymethods]
+impl Environment {
+    /// Generates a `wasmer.ImportObject` from this WASI environment,
+    /// i.e. the standard `wasi_snapshot_preview1` imports (or whatever
+    /// `wasi_version` is).
+    ///
+    /// `import_object_overrides`, when given, is merged on top: any
+    /// namespace/name it defines (for instance a Python-defined host
+    /// function registered under `env`) takes precedence over the
+    /// generated WASI import of the same name, while every other WASI
+    /// import is left untouched. This lets a WASI guest call back into
+    /// arbitrary Python functions while still receiving the standard
+    /// WASI imports it needs.
+    ///
+    /// ## Example
+    ///
+    /// ```py,ignore
+    /// import_object = wasi_env.generate_import_object(store, wasi_version, overrides)
+    /// ```
+    #[text_signature = "($self, store, wasi_version, import_object_overrides=None)"]
+    fn generate_import_object(
+        &self,
+        store: &Store,
+        wasi_version: Version,
+        import_object_overrides: Option<&ImportObject>,
+    ) -> PyResult<ImportObject> {
+        let generated = wasmer_wasi::generate_import_object_from_env(
+            &store.inner,
+            self.inner.lock().unwrap().clone(),
+            wasi_version.into(),
+        );
+
+        let mut import_object = ImportObject::from_wasmer(generated);
+
+        if let Some(overrides) = import_object_overrides {
+            import_object.extend(overrides);
+        }
+
+        Ok(import_object)
+    }
+
+    /// Reads everything the WASI guest has written to its standard
+    /// output so far. Only available if the `StateBuilder` that
+    /// produced this `Environment` called `.capture_stdout()`.
+    #[text_signature = "($self)"]
+    fn read_stdout<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        Self::read_captured_pipe(py, self.captured_stdout.as_ref())
+    }
+
+    /// Reads everything the WASI guest has written to its standard
+    /// error so far. Only available if the `StateBuilder` that
+    /// produced this `Environment` called `.capture_stderr()`.
+    #[text_signature = "($self)"]
+    fn read_stderr<'py>(&self, py: Python<'py>) -> PyResult<&'py PyBytes> {
+        Self::read_captured_pipe(py, self.captured_stderr.as_ref())
+    }
+}
+
+impl Environment {
+    fn read_captured_pipe<'py>(
+        py: Python<'py>,
+        pipe: Option<&wasmer_wasi::Pipe>,
+    ) -> PyResult<&'py PyBytes> {
+        let mut pipe = pipe
+            .cloned()
+            .ok_or_else(|| to_py_err("This `Environment` wasn't built with `.capture_stdout()`/`.capture_stderr()`"))?;
+        let mut buffer = Vec::new();
+
+        pipe.read_to_end(&mut buffer).map_err(to_py_err)?;
+
+        Ok(PyBytes::new(py, &buffer))
+    }
+}
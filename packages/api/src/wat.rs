@@ -0,0 +1,16 @@
+use crate::errors::to_py_err;
+use pyo3::{prelude::*, types::PyBytes};
+
+/// Translates WebAssembly text format (WAT) source into the
+/// WebAssembly binary format.
+pub fn wat2wasm<'py>(py: Python<'py>, wat: String) -> PyResult<&'py PyBytes> {
+    let bytes = wabt::wat2wasm(wat).map_err(to_py_err)?;
+
+    Ok(PyBytes::new(py, &bytes))
+}
+
+/// Disassembles WebAssembly binary format into the WebAssembly text
+/// format (WAT).
+pub fn wasm2wat(bytes: &PyBytes) -> PyResult<String> {
+    wabt::wasm2wat(bytes.as_bytes()).map_err(to_py_err)
+}